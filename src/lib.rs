@@ -1,25 +1,116 @@
 use std::{
+    any::Any,
+    cell::Cell,
+    collections::VecDeque,
     fmt::Display,
+    panic::{catch_unwind, AssertUnwindSafe},
     sync::{
-        atomic::{AtomicBool, Ordering},
-        mpsc::{self, Receiver, RecvError, Sender},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        mpsc::{self, Receiver, RecvError, SendError, Sender, SyncSender, TryRecvError, TrySendError},
         Arc, Condvar, Mutex,
     },
     thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use thiserror::Error;
 
 /// Possible errors when dealing with actors
 #[derive(Error, Debug)]
-pub enum ActingErr {
+pub enum ActingErr<M> {
     #[error("Actor that was contacted is dead!")]
     DeadActor,
+    #[error("Actor's mailbox is full")]
+    MailboxFull(M),
 }
 
 /// Trait that describe that a stateful interpreter
 /// of messages
 pub trait Interpreter<M> {
-    fn interpret(&mut self, message: M);
+    fn interpret(&mut self, message: M) -> Control;
+}
+
+/// What an interpreter's handling of a message tells the actor loop to do
+/// next, replacing the old per-constructor graceful/disgraceful/suicidal
+/// split with a single signal any interpreter can return
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    /// Keep consuming the mailbox as normal
+    Continue,
+    /// Stop consuming new messages, unconditionally skipping the graceful
+    /// cleanup drain even if the actor was built with `CleanupPolicy::Graceful`
+    SkipRemaining,
+    /// Stop consuming new messages; whether pending messages are then
+    /// drained is governed by the actor's `CleanupPolicy` as usual
+    Stop,
+}
+
+/// What an actor does with its still-pending mailbox once it is asked to
+/// die, set once at construction via [`Actor::new`] (or its `graceful`/
+/// `disgraceful` shorthands)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CleanupPolicy {
+    /// Drain and process all pending messages before dying
+    Graceful,
+    /// Drop all pending messages immediately
+    Disgraceful,
+}
+
+/// Trait for interpreters that can compute a reply for a message,
+/// powering the request/reply style interactions of [`Actor::ask`]
+/// and [`Actor::ask_async`] on top of the fire-and-forget mailbox.
+///
+/// Requires [`Interpreter<M>`] as a supertrait: an actor only ever runs one
+/// interpreter, reached through `tell` as well as `ask`, so a type used
+/// with `ask` must be constructible via [`Actor::graceful`] and friends
+/// too. Declaring the bound here turns a forgotten `impl Interpreter<M>`
+/// into a compile error at the `Responder` impl site instead of a
+/// confusing one where the actor is constructed.
+pub trait Responder<M>: Interpreter<M> {
+    /// The value produced in response to a message
+    type Reply;
+
+    fn respond(&mut self, message: M) -> Self::Reply;
+}
+
+/// A type-erased unit of work built by [`Actor::ask_async`]: invokes
+/// [`Responder::respond`] on the interpreter it is dispatched against and
+/// ships the result back through its paired reply channel.
+type AskJob = Box<dyn FnOnce(&mut dyn Any) + Send>;
+
+/// What actually travels through an actor's mailbox: either a plain
+/// one-way message, or a request carrying a type-erased job that
+/// will invoke [`Responder::respond`] on the running interpreter and
+/// ship the result back through its paired channel.
+enum Envelope<M> {
+    Tell(M),
+    Ask(AskJob),
+}
+
+/// Dispatches a single envelope to the interpreter: a plain `Tell` goes
+/// through `Interpreter::interpret` and yields its `Control`, while an
+/// `Ask` job invokes the `Responder::respond` call it was built with,
+/// ships the result back to the waiting `ask`/`ask_async` caller, and
+/// always yields `Control::Continue`. Shared by the thread-per-actor loop
+/// and the `System` reactor's batch runner.
+fn dispatch<M, I>(interpreter: &mut I, envelope: Envelope<M>) -> Control
+where
+    I: Interpreter<M> + 'static,
+{
+    match envelope {
+        Envelope::Tell(message) => interpreter.interpret(message),
+        Envelope::Ask(job) => {
+            job(interpreter);
+            Control::Continue
+        }
+    }
+}
+
+/// Restart policy used by [`Actor::supervised`] to recover from a
+/// panicking interpreter instead of silently killing the actor's thread.
+pub enum RestartStrategy {
+    /// Restart the single failing interpreter in place, allowing at most
+    /// `max_restarts` restarts within any sliding `within` time window.
+    OneForOne { max_restarts: u32, within: Duration },
 }
 
 /// Dummy interpreter that simply echoes the messages it receives
@@ -30,8 +121,9 @@ impl<M> Interpreter<M> for Echo
 where
     M: Display,
 {
-    fn interpret(&mut self, message: M) {
+    fn interpret(&mut self, message: M) -> Control {
         println!("Echo: {}", message);
+        Control::Continue
     }
 }
 
@@ -41,20 +133,67 @@ impl<M, F> Interpreter<M> for F
 where
     F: Fn(M),
 {
-    fn interpret(&mut self, message: M) {
-        self(message)
+    fn interpret(&mut self, message: M) -> Control {
+        self(message);
+        Control::Continue
     }
 }
 
-/// An actor which process messages of type M
-/// Internally it actually forks an OS thread
-/// that listens to incoming message and process
-/// them
+/// The sending half of an actor's mailbox: either an unbounded channel, or
+/// a capacity-bounded one that makes a producer experience backpressure
+/// instead of letting the queue grow without limit.
+enum Mailbox<T> {
+    Unbounded(Sender<T>),
+    Bounded(SyncSender<T>),
+}
+
+impl<T> Clone for Mailbox<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Mailbox::Unbounded(sender) => Mailbox::Unbounded(sender.clone()),
+            Mailbox::Bounded(sender) => Mailbox::Bounded(sender.clone()),
+        }
+    }
+}
+
+impl<T> Mailbox<T> {
+    /// Sends `value`, blocking until a free slot is available on a
+    /// bounded mailbox. An unbounded mailbox never blocks.
+    fn send(&self, value: T) -> Result<(), T> {
+        let result = match self {
+            Mailbox::Unbounded(sender) => sender.send(value),
+            Mailbox::Bounded(sender) => sender.send(value),
+        };
+        result.map_err(|SendError(value)| value)
+    }
+
+    /// Sends `value` without blocking, handing it back via
+    /// `TrySendError::Full` if a bounded mailbox is saturated.
+    fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        match self {
+            Mailbox::Unbounded(sender) => sender
+                .send(value)
+                .map_err(|SendError(value)| TrySendError::Disconnected(value)),
+            Mailbox::Bounded(sender) => sender.try_send(value),
+        }
+    }
+}
+
+/// An actor which process messages of type M.
+///
+/// Internally it either forks its own OS thread (via `graceful`,
+/// `disgraceful`, `supervised`, `bounded`) that listens to incoming
+/// messages and processes them, or, when spawned through a [`System`],
+/// shares one of the system's reactor threads with every other actor the
+/// system owns.
 pub struct Actor<M> {
-    channel: Sender<Option<M>>,
+    channel: Mailbox<Option<Envelope<M>>>,
     should_die: Arc<AtomicBool>,
     till_death: Arc<Condvar>,
     is_dead: Arc<Mutex<bool>>,
+    /// Present only for actors spawned through a [`System`]: lets `tell`,
+    /// `try_tell`, `ask`/`ask_async` and `kill` wake the shared reactor.
+    reactor: Option<Reactor>,
 }
 
 impl<M> Clone for Actor<M> {
@@ -64,6 +203,7 @@ impl<M> Clone for Actor<M> {
             should_die: self.should_die.clone(),
             till_death: self.till_death.clone(),
             is_dead: self.is_dead.clone(),
+            reactor: self.reactor.clone(),
         }
     }
 }
@@ -72,40 +212,46 @@ impl<M> Actor<M>
 where
     M: 'static + Send,
 {
-    /// Consumes all notification until it is notifier to die
-    fn loop_until_killed<I>(&self, interpreter: &mut I, consumer: &Receiver<Option<M>>)
+    /// Consumes all notification until it is notified to die or the
+    /// interpreter asks to stop. Returns `true` when the interpreter
+    /// returned `Control::SkipRemaining`, meaning the caller must
+    /// unconditionally skip its cleanup drain regardless of policy.
+    fn loop_until_killed<I>(&self, interpreter: &mut I, consumer: &Receiver<Option<Envelope<M>>>) -> bool
     where
-        I: Interpreter<M>,
+        I: Interpreter<M> + 'static,
     {
         loop {
             // Check if it has to die
             if self.should_die.load(Ordering::SeqCst) {
-                break;
+                return false;
             }
             // Wait for an incoming message
             match consumer.recv() {
                 // All channels were closed
-                Err(RecvError) => break,
+                Err(RecvError) => return false,
                 // Someone request that actor dies
-                Ok(None) => break,
+                Ok(None) => return false,
                 // Process the message
-                Ok(Some(message)) => {
-                    // Otherwise process the message
-                    interpreter.interpret(message);
-                }
+                Ok(Some(envelope)) => match dispatch(interpreter, envelope) {
+                    Control::Continue => (),
+                    Control::Stop => return false,
+                    Control::SkipRemaining => return true,
+                },
             }
         }
     }
 
     /// Cleanup all pending messages
-    fn cleanup<I>(&self, interpreter: &mut I, consumer: &Receiver<Option<M>>)
+    fn cleanup<I>(&self, interpreter: &mut I, consumer: &Receiver<Option<Envelope<M>>>)
     where
-        I: Interpreter<M>,
+        I: Interpreter<M> + 'static,
     {
-        while let Ok(message) = consumer.try_recv() {
-            match message {
+        while let Ok(envelope) = consumer.try_recv() {
+            match envelope {
                 None => (), // Do nothing since message was a kill signal
-                Some(message) => interpreter.interpret(message),
+                Some(envelope) => {
+                    dispatch(interpreter, envelope);
+                }
             }
         }
     }
@@ -121,27 +267,30 @@ where
         till_death.notify_all();
     }
 
-    /// Creates and returns and actor that gracefully
-    /// process all pending messages when asked to die
-    pub fn graceful<I>(interpreter: I) -> Self
+    /// Creates an actor running `interpreter`, draining or dropping
+    /// pending messages on death according to `cleanup_policy`
+    pub fn new<I>(interpreter: I, cleanup_policy: CleanupPolicy) -> Self
     where
         I: Interpreter<M> + Send + 'static,
     {
         let (channel, consumer) = mpsc::channel();
         let actor = Self {
-            channel,
+            channel: Mailbox::Unbounded(channel),
             should_die: Arc::new(AtomicBool::new(false)),
             till_death: Arc::new(Condvar::default()),
             is_dead: Arc::new(Mutex::new(false)),
+            reactor: None,
         };
         let cloned_actor = actor.clone();
 
         let mut interpreter = interpreter;
         thread::spawn(move || {
             // Main message handling loop
-            actor.loop_until_killed(&mut interpreter, &consumer);
-            // Cleaning up
-            actor.cleanup(&mut interpreter, &consumer);
+            let skip_cleanup = actor.loop_until_killed(&mut interpreter, &consumer);
+            // Cleaning up, unless skipped by policy or by Control::SkipRemaining
+            if cleanup_policy == CleanupPolicy::Graceful && !skip_cleanup {
+                actor.cleanup(&mut interpreter, &consumer);
+            }
             // Dies & notify all waiters
             actor.die();
         });
@@ -149,27 +298,121 @@ where
         cloned_actor
     }
 
-    /// Creates and returns and actor that disgracefully
-    /// ignore all pending messages when asked to die
+    /// Creates and returns an actor that gracefully process all pending
+    /// messages when asked to die. Shorthand for
+    /// `Actor::new(interpreter, CleanupPolicy::Graceful)`
+    pub fn graceful<I>(interpreter: I) -> Self
+    where
+        I: Interpreter<M> + Send + 'static,
+    {
+        Self::new(interpreter, CleanupPolicy::Graceful)
+    }
+
+    /// Creates and returns an actor that disgracefully ignores all pending
+    /// messages when asked to die. Shorthand for
+    /// `Actor::new(interpreter, CleanupPolicy::Disgraceful)`
     pub fn disgraceful<I>(interpreter: I) -> Self
     where
         I: Interpreter<M> + Send + 'static,
+    {
+        Self::new(interpreter, CleanupPolicy::Disgraceful)
+    }
+
+    /// Creates an actor whose interpreter is (re)built from `factory` and
+    /// that survives panics raised out of `Interpreter::interpret`: the
+    /// panic is caught, logged, and the message that triggered it is
+    /// dropped. The interpreter is then rebuilt from `factory` as long as
+    /// `strategy`'s restart budget for the current sliding window is not
+    /// exhausted; once it is, the actor dies like any other through the
+    /// usual `cleanup`/`die` path.
+    pub fn supervised<F, I>(factory: F, strategy: RestartStrategy) -> Self
+    where
+        F: Fn() -> I + Send + 'static,
+        I: Interpreter<M> + Send + 'static,
     {
         let (channel, consumer) = mpsc::channel();
         let actor = Self {
-            channel,
+            channel: Mailbox::Unbounded(channel),
             should_die: Arc::new(AtomicBool::new(false)),
             till_death: Arc::new(Condvar::default()),
             is_dead: Arc::new(Mutex::new(false)),
+            reactor: None,
+        };
+        let cloned_actor = actor.clone();
+
+        thread::spawn(move || {
+            let RestartStrategy::OneForOne { max_restarts, within } = strategy;
+            let mut restarts: Vec<Instant> = Vec::new();
+            let mut interpreter = factory();
+            let mut skip_cleanup = false;
+
+            loop {
+                if actor.should_die.load(Ordering::SeqCst) {
+                    break;
+                }
+                match catch_unwind(AssertUnwindSafe(|| {
+                    actor.loop_until_killed(&mut interpreter, &consumer)
+                })) {
+                    Ok(skip) => {
+                        skip_cleanup = skip;
+                        break;
+                    }
+                    Err(_) => {
+                        eprintln!("theatre: interpreter panicked, checking restart budget");
+                        let now = Instant::now();
+                        restarts.retain(|at| now.duration_since(*at) <= within);
+                        restarts.push(now);
+                        if restarts.len() as u32 > max_restarts {
+                            eprintln!("theatre: restart budget exhausted, actor is dying");
+                            break;
+                        }
+                        interpreter = factory();
+                    }
+                }
+            }
+            // Cleaning up. A message still in the mailbox can panic just as
+            // easily as one processed by the main loop, so this drain gets
+            // the same catch_unwind treatment: a panic here must not
+            // unwind past `actor.die()` below, or `wait()` would hang
+            // forever waiting for a notification that never comes.
+            if !skip_cleanup
+                && catch_unwind(AssertUnwindSafe(|| actor.cleanup(&mut interpreter, &consumer))).is_err()
+            {
+                eprintln!("theatre: interpreter panicked while draining pending messages during cleanup");
+            }
+            // Dies & notify all waiters
+            actor.die();
+        });
+
+        cloned_actor
+    }
+
+    /// Creates a gracefully-shutting-down actor backed by a mailbox that
+    /// holds at most `capacity` pending messages: once full, `tell` blocks
+    /// waiting for a free slot instead of growing without bound, and
+    /// `try_tell` reports the saturation instead of blocking.
+    pub fn bounded<I>(interpreter: I, capacity: usize) -> Self
+    where
+        I: Interpreter<M> + Send + 'static,
+    {
+        let (channel, consumer) = mpsc::sync_channel(capacity);
+        let actor = Self {
+            channel: Mailbox::Bounded(channel),
+            should_die: Arc::new(AtomicBool::new(false)),
+            till_death: Arc::new(Condvar::default()),
+            is_dead: Arc::new(Mutex::new(false)),
+            reactor: None,
         };
         let cloned_actor = actor.clone();
 
         let mut interpreter = interpreter;
         thread::spawn(move || {
             // Main message handling loop
-            actor.loop_until_killed(&mut interpreter, &consumer);
+            let skip_cleanup = actor.loop_until_killed(&mut interpreter, &consumer);
             // Cleaning up
-            // No cleaning up since we are disgraceful
+            if !skip_cleanup {
+                actor.cleanup(&mut interpreter, &consumer);
+            }
             // Dies & notify all waiters
             actor.die();
         });
@@ -177,12 +420,6 @@ where
         cloned_actor
     }
 
-    /// Creates and returns and actor that gracefully
-    /// process all pending messages when asked to die
-    pub fn suicidal() -> Self {
-        unimplemented!()
-    }
-
     /// Waits indefinitely until the actor is declared dead
     /// Renounce on its ability to send messages
     /// Will deadlock if actor is alread dead
@@ -199,23 +436,491 @@ where
         }
     }
 
-    /// Send a message to an actor
-    pub fn tell(&self, message: M) -> Result<(), ActingErr> {
+    /// Wakes this actor's [`System`] reactor, if it has one, so that a
+    /// worker thread picks up the message just enqueued. A no-op for
+    /// thread-per-actor constructors, which already own a dedicated thread
+    /// blocked on their mailbox.
+    fn wake_reactor(&self) {
+        if let Some(reactor) = &self.reactor {
+            if !reactor.scheduled.swap(true, Ordering::SeqCst) {
+                reactor.system.enqueue(reactor.core.clone());
+            }
+        }
+    }
+
+    /// Send a message to an actor, blocking until the mailbox has a free
+    /// slot if it is bounded and currently full
+    pub fn tell(&self, message: M) -> Result<(), ActingErr<M>> {
         self.channel
-            .send(Some(message))
+            .send(Some(Envelope::Tell(message)))
+            .map_err(|_| ActingErr::DeadActor)?;
+        self.wake_reactor();
+        Ok(())
+    }
+
+    /// Like [`Actor::tell`] but never blocks: on a bounded mailbox that is
+    /// currently full, the message is handed back via
+    /// `ActingErr::MailboxFull` instead of waiting for a free slot.
+    pub fn try_tell(&self, message: M) -> Result<(), ActingErr<M>> {
+        match self.channel.try_send(Some(Envelope::Tell(message))) {
+            Ok(()) => {
+                self.wake_reactor();
+                Ok(())
+            }
+            Err(TrySendError::Full(envelope)) => match envelope {
+                Some(Envelope::Tell(message)) => Err(ActingErr::MailboxFull(message)),
+                _ => unreachable!("try_tell only ever sends a Tell envelope"),
+            },
+            Err(TrySendError::Disconnected(_)) => Err(ActingErr::DeadActor),
+        }
+    }
+
+    /// Sends a message and blocks until the actor's interpreter, whose
+    /// concrete type `I` must be named at the call site, has produced a
+    /// reply through [`Responder::respond`].
+    ///
+    /// Returns `Err(ActingErr::DeadActor)` if the actor is already dead
+    /// or dies before answering.
+    pub fn ask<I>(&self, message: M) -> Result<I::Reply, ActingErr<M>>
+    where
+        I: Responder<M> + 'static,
+        I::Reply: Send + 'static,
+    {
+        self.ask_async::<I>(message)?
+            .recv()
             .map_err(|_| ActingErr::DeadActor)
     }
 
+    /// Non-blocking variant of [`Actor::ask`]: sends the message and
+    /// immediately returns a [`Receiver`] that yields the reply once the
+    /// actor has processed it.
+    pub fn ask_async<I>(&self, message: M) -> Result<Receiver<I::Reply>, ActingErr<M>>
+    where
+        I: Responder<M> + 'static,
+        I::Reply: Send + 'static,
+    {
+        let (reply_sender, reply_receiver) = mpsc::channel();
+        let job: AskJob = Box::new(move |interpreter| {
+            // Naming the wrong `I` at the call site is a caller bug the
+            // type system can't catch here (the interpreter is type-erased
+            // behind `dyn Any`), so guard the downcast+respond call with
+            // catch_unwind: a mismatch panics and drops `reply_sender`
+            // without sending, which `ask`/`ask_async` already reports as
+            // `ActingErr::DeadActor` for that one call, instead of
+            // unwinding into the actor's dispatch loop and killing every
+            // clone over a single bad `ask`.
+            let reply = catch_unwind(AssertUnwindSafe(|| {
+                let interpreter = interpreter
+                    .downcast_mut::<I>()
+                    .expect("Actor::ask called with a type that does not match its interpreter");
+                interpreter.respond(message)
+            }));
+            match reply {
+                Ok(reply) => {
+                    let _ = reply_sender.send(reply);
+                }
+                Err(_) => eprintln!("theatre: ask called with a type that does not match its interpreter"),
+            }
+        });
+        self.channel
+            .send(Some(Envelope::Ask(job)))
+            .map_err(|_| ActingErr::DeadActor)?;
+        self.wake_reactor();
+        Ok(reply_receiver)
+    }
+
     /// Kills an actor
     /// This function returns `Ok(())` if it succesfully kills it
     /// And error explaining the reason it could not do if not
     /// Trying to kill a dead actor does not do anything
     pub fn kill(&self) {
         self.should_die.store(true, Ordering::SeqCst);
-        // Signal the actor that he has to die
-        match self.channel.send(None) {
-            Err(_) => (), // Actor already dead so do nothing
-            Ok(_) => (),  // Sent a dummy message to process
+        // Signal the actor that he has to die. Never blocks: on a full
+        // bounded mailbox the kill signal is dropped, but `should_die` is
+        // already set so the loop exits on its next `recv`.
+        let _ = self.channel.try_send(None);
+        self.wake_reactor();
+    }
+
+    /// Sends `message` to this actor after `after` has elapsed, via a
+    /// lightweight timer thread
+    pub fn tell_later(&self, message: M, after: Duration) {
+        let actor = self.clone();
+        thread::spawn(move || {
+            thread::sleep(after);
+            let _ = actor.tell(message);
+        });
+    }
+
+    /// Repeatedly sends a clone of `message` to this actor, once every
+    /// `every`, until the returned [`IntervalHandle`] is cancelled or the
+    /// actor dies
+    pub fn tell_interval(&self, message: M, every: Duration) -> IntervalHandle
+    where
+        M: Clone,
+    {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let handle = IntervalHandle {
+            cancelled: cancelled.clone(),
+        };
+        let actor = self.clone();
+        thread::spawn(move || loop {
+            thread::sleep(every);
+            if cancelled.load(Ordering::SeqCst) || actor.should_die.load(Ordering::SeqCst) {
+                break;
+            }
+            if actor.tell(message.clone()).is_err() {
+                break;
+            }
+        });
+        handle
+    }
+}
+
+/// An actor's reactor-backed unit of work: draining its mailbox into its
+/// interpreter. Type-erased over the actor's message and interpreter types
+/// so a single [`System`] ready-queue can hold every kind of actor it owns.
+trait Scheduled: Send + Sync {
+    /// Processes up to a bounded batch of pending messages. Returns `true`
+    /// when the batch limit was hit (the mailbox may or may not still hold
+    /// messages), in which case the caller should re-enqueue this actor
+    /// right away to keep the system fair instead of waiting for the next
+    /// `tell`.
+    fn run_batch(&self) -> bool;
+}
+
+/// How many messages a [`System`] worker drains from one actor before
+/// yielding it back to the ready-queue, so one busy actor cannot starve
+/// the others sharing the same reactor threads.
+const REACTOR_BATCH_SIZE: usize = 32;
+
+/// The mutable state a reactor-backed actor's `run_batch` needs: its
+/// mailbox and its interpreter, behind a single lock so the `Scheduled`
+/// trait object can be `Sync` despite `Receiver` not being one.
+struct ActorCore<M, I> {
+    state: Mutex<(Receiver<Option<Envelope<M>>>, I)>,
+    should_die: Arc<AtomicBool>,
+    till_death: Arc<Condvar>,
+    is_dead: Arc<Mutex<bool>>,
+    scheduled: Arc<AtomicBool>,
+    cleanup_policy: CleanupPolicy,
+    /// Set when the interpreter returns `Control::SkipRemaining`, so the
+    /// cleanup drain below is skipped unconditionally, the same way
+    /// `loop_until_killed` does for the thread-per-actor constructors.
+    skip_cleanup: AtomicBool,
+}
+
+impl<M, I> Scheduled for ActorCore<M, I>
+where
+    M: 'static + Send,
+    I: Interpreter<M> + Send + 'static,
+{
+    fn run_batch(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let (consumer, interpreter) = &mut *state;
+
+        let mut processed = 0;
+        loop {
+            if self.should_die.load(Ordering::SeqCst) {
+                break;
+            }
+            if processed >= REACTOR_BATCH_SIZE {
+                drop(state);
+                return true;
+            }
+            match consumer.try_recv() {
+                Ok(Some(envelope)) => {
+                    processed += 1;
+                    match dispatch(interpreter, envelope) {
+                        Control::Continue => (),
+                        Control::Stop => self.should_die.store(true, Ordering::SeqCst),
+                        Control::SkipRemaining => {
+                            self.skip_cleanup.store(true, Ordering::SeqCst);
+                            self.should_die.store(true, Ordering::SeqCst);
+                        }
+                    }
+                }
+                Ok(None) => self.should_die.store(true, Ordering::SeqCst),
+                Err(TryRecvError::Disconnected) => self.should_die.store(true, Ordering::SeqCst),
+                Err(TryRecvError::Empty) => {
+                    // The mailbox looked empty. Clear `scheduled` *before*
+                    // checking again: a `tell` landing between our
+                    // `try_recv` above and this `store` may have observed
+                    // `scheduled` still `true` and skipped re-enqueueing
+                    // us, trusting we'd notice its message ourselves. If
+                    // we find one on the recheck, reclaim `scheduled`
+                    // before processing it so that a concurrent `tell`
+                    // unambiguously re-enqueues us instead (redundant
+                    // wakeups are harmless; lost ones are not).
+                    self.scheduled.store(false, Ordering::SeqCst);
+                    match consumer.try_recv() {
+                        Ok(Some(envelope)) => {
+                            self.scheduled.store(true, Ordering::SeqCst);
+                            processed += 1;
+                            match dispatch(interpreter, envelope) {
+                                Control::Continue => (),
+                                Control::Stop => self.should_die.store(true, Ordering::SeqCst),
+                                Control::SkipRemaining => {
+                                    self.skip_cleanup.store(true, Ordering::SeqCst);
+                                    self.should_die.store(true, Ordering::SeqCst);
+                                }
+                            }
+                        }
+                        Ok(None) => self.should_die.store(true, Ordering::SeqCst),
+                        Err(TryRecvError::Disconnected) => {
+                            self.should_die.store(true, Ordering::SeqCst)
+                        }
+                        Err(TryRecvError::Empty) => {
+                            drop(state);
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Dying: drain the rest of the mailbox first if the policy says so,
+        // unless `Control::SkipRemaining` asked to unconditionally skip it
+        if self.cleanup_policy == CleanupPolicy::Graceful && !self.skip_cleanup.load(Ordering::SeqCst) {
+            while let Ok(envelope) = consumer.try_recv() {
+                if let Some(envelope) = envelope {
+                    dispatch(interpreter, envelope);
+                }
+            }
+        }
+        drop(state);
+
+        self.scheduled.store(false, Ordering::SeqCst);
+        let mut guard = self.is_dead.lock().unwrap();
+        *guard = true;
+        self.till_death.notify_all();
+        false
+    }
+}
+
+/// Links an [`Actor`] spawned through a [`System`] back to its reactor:
+/// its type-erased unit of work, whether it is currently queued or being
+/// processed, and the system whose ready-queue/condvar it wakes.
+#[derive(Clone)]
+struct Reactor {
+    core: Arc<dyn Scheduled>,
+    scheduled: Arc<AtomicBool>,
+    system: Arc<SystemInner>,
+}
+
+struct SystemInner {
+    ready: Mutex<VecDeque<Arc<dyn Scheduled>>>,
+    has_work: Condvar,
+    shutdown: AtomicBool,
+}
+
+impl SystemInner {
+    fn enqueue(&self, actor: Arc<dyn Scheduled>) {
+        self.ready.lock().unwrap().push_back(actor);
+        self.has_work.notify_one();
+    }
+}
+
+/// A fixed pool of reactor threads shared by every actor it spawns,
+/// instead of each actor forking its own OS thread the way `Actor::graceful`
+/// and friends do. Lets the crate scale to large actor counts: a `tell`
+/// pushes the message onto the actor's own mailbox and, if the actor isn't
+/// already queued, pushes it onto the system's global ready-queue and
+/// wakes a worker; a worker pops an actor, drains a bounded batch of its
+/// messages, and re-enqueues it if that batch hit its limit. `kill`/`wait`/
+/// `is_dead` behave exactly as they do for thread-per-actor constructors,
+/// including honoring the `CleanupPolicy` passed to [`System::spawn`].
+pub struct System {
+    inner: Arc<SystemInner>,
+}
+
+impl System {
+    /// Starts `num_threads` reactor threads sharing one ready-queue
+    pub fn new(num_threads: usize) -> Self {
+        let inner = Arc::new(SystemInner {
+            ready: Mutex::new(VecDeque::new()),
+            has_work: Condvar::new(),
+            shutdown: AtomicBool::new(false),
+        });
+
+        for _ in 0..num_threads {
+            let inner = inner.clone();
+            thread::spawn(move || Self::worker_loop(inner));
+        }
+
+        Self { inner }
+    }
+
+    fn worker_loop(system: Arc<SystemInner>) {
+        loop {
+            let actor = {
+                let mut ready = system.ready.lock().unwrap();
+                loop {
+                    if system.shutdown.load(Ordering::SeqCst) {
+                        return;
+                    }
+                    if let Some(actor) = ready.pop_front() {
+                        break actor;
+                    }
+                    ready = system.has_work.wait(ready).unwrap();
+                }
+            };
+
+            if actor.run_batch() {
+                system.enqueue(actor);
+            }
+        }
+    }
+
+    /// Spawns an actor whose mailbox is drained by this system's reactor
+    /// threads rather than by a thread of its own, dying according to
+    /// `cleanup_policy` like any other actor once killed
+    pub fn spawn<M, I>(&self, interpreter: I, cleanup_policy: CleanupPolicy) -> Actor<M>
+    where
+        M: 'static + Send,
+        I: Interpreter<M> + Send + 'static,
+    {
+        let (channel, consumer) = mpsc::channel();
+        let should_die = Arc::new(AtomicBool::new(false));
+        let till_death = Arc::new(Condvar::default());
+        let is_dead = Arc::new(Mutex::new(false));
+        let scheduled = Arc::new(AtomicBool::new(false));
+
+        let core: Arc<dyn Scheduled> = Arc::new(ActorCore {
+            state: Mutex::new((consumer, interpreter)),
+            should_die: should_die.clone(),
+            till_death: till_death.clone(),
+            is_dead: is_dead.clone(),
+            scheduled: scheduled.clone(),
+            cleanup_policy,
+            skip_cleanup: AtomicBool::new(false),
+        });
+
+        Actor {
+            channel: Mailbox::Unbounded(channel),
+            should_die,
+            till_death,
+            is_dead,
+            reactor: Some(Reactor {
+                core,
+                scheduled,
+                system: self.inner.clone(),
+            }),
+        }
+    }
+}
+
+/// Handle to a running [`Actor::tell_interval`] timer, letting the caller
+/// stop the recurring send
+pub struct IntervalHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl IntervalHandle {
+    /// Stops the timer; a tick still mid-sleep sends nothing, though one that
+    /// has already passed its cancellation check may still be delivered
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+}
+
+/// How a [`Pool`] picks which worker handles the next message
+#[derive(Clone, Copy, Debug)]
+pub enum DispatchMode {
+    /// Cycle through workers in order, via an atomic counter modulo `N`
+    RoundRobin,
+    /// Pick a worker uniformly at random, via a fast per-thread PRNG
+    Random,
+}
+
+/// Picks a pseudo-random index in `0..len` using a per-thread xorshift64
+/// generator, avoiding the cost (and dependency) of a full-blown RNG for
+/// what is just load-balancing across pool workers.
+fn random_index(len: usize) -> usize {
+    thread_local! {
+        static STATE: Cell<u64> = const { Cell::new(0) };
+    }
+    static SEED_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    STATE.with(|state| {
+        let mut x = state.get();
+        if x == 0 {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_nanos() as u64)
+                .unwrap_or(0x9E37_79B9_7F4A_7C15);
+            let salt = SEED_COUNTER.fetch_add(1, Ordering::Relaxed);
+            x = (nanos ^ salt.wrapping_mul(0x2545_F491_4F6C_DD1D)) | 1;
+        }
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        state.set(x);
+        (x as usize) % len
+    })
+}
+
+/// A fixed-size group of worker actors, all running the same interpreter,
+/// that load-balances incoming messages across them. Exposes the same
+/// `tell`/`kill`/`wait` surface as [`Actor`], letting CPU-bound interpreters
+/// scale horizontally without the caller hand-rolling fan-out.
+pub struct Pool<M> {
+    workers: Vec<Actor<M>>,
+    mode: DispatchMode,
+    next: AtomicUsize,
+}
+
+impl<M> Pool<M>
+where
+    M: 'static + Send,
+{
+    /// Spawns `size` worker actors, each built by calling `factory` once,
+    /// dispatched according to `mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`: a pool with no workers has nothing to
+    /// dispatch `tell` to.
+    pub fn new<F, I>(size: usize, mode: DispatchMode, factory: F) -> Self
+    where
+        F: Fn() -> I,
+        I: Interpreter<M> + Send + 'static,
+    {
+        assert!(size > 0, "Pool::new requires at least one worker");
+        let workers = (0..size).map(|_| Actor::graceful(factory())).collect();
+        Self {
+            workers,
+            mode,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Picks the worker that should handle the next message
+    fn pick(&self) -> &Actor<M> {
+        let index = match self.mode {
+            DispatchMode::RoundRobin => self.next.fetch_add(1, Ordering::SeqCst) % self.workers.len(),
+            DispatchMode::Random => random_index(self.workers.len()),
+        };
+        &self.workers[index]
+    }
+
+    /// Sends a message to one worker, chosen according to the pool's
+    /// [`DispatchMode`]
+    pub fn tell(&self, message: M) -> Result<(), ActingErr<M>> {
+        self.pick().tell(message)
+    }
+
+    /// Kills every worker in the pool
+    pub fn kill(&self) {
+        for worker in &self.workers {
+            worker.kill();
+        }
+    }
+
+    /// Blocks until every worker has died
+    pub fn wait(self) {
+        for worker in self.workers {
+            worker.wait();
         }
     }
 }
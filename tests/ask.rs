@@ -0,0 +1,79 @@
+use theatre::{ActingErr, Actor, Control, Interpreter, Responder};
+
+struct Double;
+
+impl Interpreter<i32> for Double {
+    fn interpret(&mut self, message: i32) -> Control {
+        self.respond(message);
+        Control::Continue
+    }
+}
+
+impl Responder<i32> for Double {
+    type Reply = i32;
+
+    fn respond(&mut self, message: i32) -> i32 {
+        message * 2
+    }
+}
+
+struct Negate;
+
+impl Interpreter<i32> for Negate {
+    fn interpret(&mut self, message: i32) -> Control {
+        self.respond(message);
+        Control::Continue
+    }
+}
+
+impl Responder<i32> for Negate {
+    type Reply = i32;
+
+    fn respond(&mut self, message: i32) -> i32 {
+        -message
+    }
+}
+
+#[test]
+fn test_ask_returns_reply() {
+    let actor: Actor<i32> = Actor::graceful(Double);
+    let reply = actor.ask::<Double>(21).unwrap();
+    assert_eq!(reply, 42);
+    actor.kill();
+    actor.wait();
+}
+
+#[test]
+fn test_ask_async_returns_reply() {
+    let actor: Actor<i32> = Actor::graceful(Double);
+    let receiver = actor.ask_async::<Double>(10).unwrap();
+    assert_eq!(receiver.recv().unwrap(), 20);
+    actor.kill();
+    actor.wait();
+}
+
+#[test]
+fn test_ask_on_dead_actor_fails() {
+    let actor: Actor<i32> = Actor::graceful(Double);
+    actor.kill();
+    actor.clone().wait();
+    assert!(matches!(actor.ask::<Double>(1), Err(ActingErr::DeadActor)));
+}
+
+#[test]
+fn test_ask_wrong_type_does_not_kill_actor() {
+    // Naming the wrong interpreter type at the call site is a caller bug
+    // that can't be caught at compile time (the interpreter is type-erased
+    // behind `dyn Any` until the job runs). It must fail just that one
+    // `ask`, not take the whole actor down with it.
+    let actor: Actor<i32> = Actor::graceful(Double);
+
+    let wrong = actor.ask::<Negate>(5);
+    assert!(matches!(wrong, Err(ActingErr::DeadActor)));
+
+    let reply = actor.ask::<Double>(5).unwrap();
+    assert_eq!(reply, 10);
+
+    actor.kill();
+    actor.wait();
+}
@@ -0,0 +1,96 @@
+use std::{
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+use theatre::{ActingErr, Actor, Control, Interpreter};
+
+#[inline]
+fn sleep_ms(count: u64) {
+    thread::sleep(Duration::from_millis(count));
+}
+
+/// Blocks inside `interpret` until the test sends a signal on `proceed`,
+/// so a test can hold a message "in flight" and keep the bounded mailbox
+/// saturated for as long as it needs to.
+struct Gate {
+    proceed: Receiver<()>,
+    processed: Sender<i32>,
+}
+
+impl Interpreter<i32> for Gate {
+    fn interpret(&mut self, message: i32) -> Control {
+        let _ = self.proceed.recv();
+        let _ = self.processed.send(message);
+        Control::Continue
+    }
+}
+
+#[test]
+fn test_bounded_try_tell_reports_full_then_recovers() {
+    let (proceed_tx, proceed_rx) = mpsc::channel();
+    let (processed_tx, processed_rx) = mpsc::channel();
+    let actor: Actor<i32> = Actor::bounded(
+        Gate {
+            proceed: proceed_rx,
+            processed: processed_tx,
+        },
+        2,
+    );
+
+    actor.tell(1).unwrap();
+    sleep_ms(100); // let the actor pick message 1 up and block inside interpret()
+
+    actor.tell(2).unwrap(); // fills buffer slot 1 of 2
+    actor.tell(3).unwrap(); // fills buffer slot 2 of 2; now saturated
+
+    match actor.try_tell(4) {
+        Err(ActingErr::MailboxFull(4)) => (),
+        other => panic!("expected MailboxFull(4), got {:?}", other),
+    }
+
+    proceed_tx.send(()).unwrap(); // let message 1 finish
+    sleep_ms(100); // actor picks up message 2, freeing a slot
+
+    actor.try_tell(4).unwrap();
+
+    for _ in 0..3 {
+        proceed_tx.send(()).unwrap();
+    }
+    actor.kill();
+    actor.wait();
+
+    let mut processed: Vec<i32> = processed_rx.try_iter().collect();
+    processed.sort_unstable();
+    assert_eq!(processed, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_bounded_kill_falls_back_to_should_die_on_full_mailbox() {
+    let (proceed_tx, proceed_rx) = mpsc::channel();
+    let (processed_tx, _processed_rx) = mpsc::channel();
+    let actor: Actor<i32> = Actor::bounded(
+        Gate {
+            proceed: proceed_rx,
+            processed: processed_tx,
+        },
+        1,
+    );
+
+    actor.tell(1).unwrap();
+    sleep_ms(100); // let the actor pick message 1 up and block inside interpret()
+
+    actor.tell(2).unwrap(); // fills the one buffer slot
+
+    // kill()'s non-blocking try_send(None) can't fit into the saturated
+    // mailbox; it must fall back to setting `should_die` so the loop
+    // still exits on its next check instead of hanging.
+    actor.kill();
+    proceed_tx.send(()).unwrap(); // let message 1 finish so the loop rechecks should_die
+    // Bounded actors shut down gracefully, so message 2 is still processed
+    // by the cleanup drain; let it through as well.
+    proceed_tx.send(()).unwrap();
+
+    actor.wait();
+}
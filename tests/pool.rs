@@ -0,0 +1,74 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+};
+
+use theatre::{Control, DispatchMode, Interpreter, Pool};
+
+struct Tagger {
+    id: usize,
+    sender: mpsc::Sender<usize>,
+}
+
+impl Interpreter<i32> for Tagger {
+    fn interpret(&mut self, _message: i32) -> Control {
+        let _ = self.sender.send(self.id);
+        Control::Continue
+    }
+}
+
+fn tagged_pool(size: usize, mode: DispatchMode, sender: mpsc::Sender<usize>) -> Pool<i32> {
+    let next_id = Arc::new(AtomicUsize::new(0));
+    Pool::new(size, mode, move || Tagger {
+        id: next_id.fetch_add(1, Ordering::SeqCst),
+        sender: sender.clone(),
+    })
+}
+
+#[test]
+fn test_pool_round_robin_spreads_evenly() {
+    let (sender, receiver) = mpsc::channel();
+    let pool = tagged_pool(3, DispatchMode::RoundRobin, sender);
+
+    for _ in 0..9 {
+        pool.tell(0).unwrap();
+    }
+
+    // Worker threads may report back in any order, but round-robin over
+    // 3 workers for 9 messages must hit each worker exactly 3 times.
+    let mut hits: Vec<usize> = (0..9).map(|_| receiver.recv().unwrap()).collect();
+    hits.sort_unstable();
+    assert_eq!(hits, vec![0, 0, 0, 1, 1, 1, 2, 2, 2]);
+
+    pool.kill();
+    pool.wait();
+}
+
+#[test]
+fn test_pool_random_reaches_every_worker() {
+    let (sender, receiver) = mpsc::channel();
+    let pool = tagged_pool(4, DispatchMode::Random, sender);
+
+    for _ in 0..200 {
+        pool.tell(0).unwrap();
+    }
+
+    let hits: HashSet<usize> = (0..200).map(|_| receiver.recv().unwrap()).collect();
+    // With 200 random picks among 4 workers, every worker being hit at
+    // least once is overwhelmingly likely; this only flakes with
+    // probability on the order of 4 * 0.75^200.
+    assert_eq!(hits.len(), 4);
+
+    pool.kill();
+    pool.wait();
+}
+
+#[test]
+#[should_panic]
+fn test_pool_zero_workers_panics() {
+    let (sender, _receiver) = mpsc::channel();
+    let _: Pool<i32> = tagged_pool(0, DispatchMode::RoundRobin, sender);
+}
@@ -2,19 +2,23 @@ use std::thread;
 use std::time::Duration;
 
 use theatre::Actor;
-use theatre::SuicidalActor;
-use theatre::SuicidalInterpreter;
+use theatre::Control;
+use theatre::Interpreter;
 
 pub struct CoutingIntepreter(u32);
 
-impl SuicidalInterpreter<u32> for CoutingIntepreter {
-    fn process(&mut self, message: u32) -> bool {
+impl Interpreter<u32> for CoutingIntepreter {
+    fn interpret(&mut self, message: u32) -> Control {
         println!(
             "It's the nb. {}, message I've received! \n  => {}",
             self.0, message
         );
         self.0 += 1;
-        self.0 % 2 == 0
+        if self.0.is_multiple_of(2) {
+            Control::Stop
+        } else {
+            Control::Continue
+        }
     }
 }
 
@@ -22,10 +26,10 @@ impl SuicidalInterpreter<u32> for CoutingIntepreter {
 #[should_panic]
 fn disgracefully_close() {
     let counter = CoutingIntepreter(0);
-    let actor = SuicidalActor::new(counter);
+    let actor = Actor::disgraceful(counter);
     actor.tell(3).unwrap();
     actor.tell(5).unwrap();
-    // Actor will kill himself after receiving an even number
+    // Actor will stop itself after receiving an even number of messages
     actor.tell(2).unwrap();
     thread::sleep(Duration::from_millis(500));
     // Actor should be dead so this should fail
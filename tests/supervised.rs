@@ -0,0 +1,69 @@
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use theatre::{Actor, Control, Interpreter, RestartStrategy};
+
+struct PanicsOnZero {
+    processed: Arc<AtomicU32>,
+}
+
+impl Interpreter<i32> for PanicsOnZero {
+    fn interpret(&mut self, message: i32) -> Control {
+        if message == 0 {
+            panic!("PanicsOnZero refuses to process zero");
+        }
+        self.processed.fetch_add(1, Ordering::SeqCst);
+        Control::Continue
+    }
+}
+
+#[test]
+fn test_supervised_restarts_after_panic() {
+    let processed = Arc::new(AtomicU32::new(0));
+    let factory_processed = processed.clone();
+    let actor: Actor<i32> = Actor::supervised(
+        move || PanicsOnZero {
+            processed: factory_processed.clone(),
+        },
+        RestartStrategy::OneForOne {
+            max_restarts: 3,
+            within: Duration::from_secs(10),
+        },
+    );
+
+    actor.tell(1).unwrap();
+    actor.tell(0).unwrap(); // panics; interpreter is rebuilt from the factory
+    actor.tell(2).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    assert_eq!(processed.load(Ordering::SeqCst), 2);
+
+    actor.kill();
+    actor.wait();
+}
+
+#[test]
+fn test_supervised_dies_once_restart_budget_exhausted() {
+    let processed = Arc::new(AtomicU32::new(0));
+    let actor: Actor<i32> = Actor::supervised(
+        move || PanicsOnZero {
+            processed: processed.clone(),
+        },
+        RestartStrategy::OneForOne {
+            max_restarts: 1,
+            within: Duration::from_secs(10),
+        },
+    );
+
+    actor.tell(0).unwrap();
+    actor.tell(0).unwrap();
+    // The second panic exceeds the restart budget of 1, so the actor
+    // should now die instead of restarting again.
+    actor.wait();
+}
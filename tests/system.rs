@@ -0,0 +1,106 @@
+use std::{sync::mpsc, thread};
+
+use theatre::{CleanupPolicy, Control, Interpreter, System};
+
+struct Collect(mpsc::Sender<i32>);
+
+impl Interpreter<i32> for Collect {
+    fn interpret(&mut self, message: i32) -> Control {
+        let _ = self.0.send(message);
+        Control::Continue
+    }
+}
+
+#[test]
+fn test_system_delivers_messages_from_many_threads() {
+    let system = System::new(4);
+    let (sender, receiver) = mpsc::channel();
+    let actor = system.spawn(Collect(sender), CleanupPolicy::Graceful);
+
+    let senders: Vec<_> = (0..8)
+        .map(|n| {
+            let actor = actor.clone();
+            thread::spawn(move || {
+                for k in 0..50 {
+                    actor.tell(n * 50 + k).unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in senders {
+        handle.join().unwrap();
+    }
+
+    // Blocks until all 400 messages have made it through the reactor,
+    // regardless of which worker thread or batch drained each one.
+    let mut received: Vec<i32> = receiver.iter().take(400).collect();
+    received.sort_unstable();
+    let expected: Vec<i32> = (0..400).collect();
+    assert_eq!(received, expected);
+
+    actor.kill();
+    actor.wait();
+}
+
+#[test]
+fn test_system_graceful_drains_pending_on_kill() {
+    let system = System::new(2);
+    let (sender, receiver) = mpsc::channel();
+    let actor = system.spawn(Collect(sender), CleanupPolicy::Graceful);
+
+    for k in 0..20 {
+        actor.tell(k).unwrap();
+    }
+    actor.kill();
+    actor.wait();
+
+    let received: Vec<i32> = receiver.try_iter().collect();
+    assert_eq!(received.len(), 20);
+}
+
+struct SkipOn999(mpsc::Sender<i32>);
+
+impl Interpreter<i32> for SkipOn999 {
+    fn interpret(&mut self, message: i32) -> Control {
+        let _ = self.0.send(message);
+        if message == 999 {
+            Control::SkipRemaining
+        } else {
+            Control::Continue
+        }
+    }
+}
+
+#[test]
+fn test_system_skip_remaining_drops_pending_even_when_graceful() {
+    let system = System::new(1);
+    let (sender, receiver) = mpsc::channel();
+    let actor = system.spawn(SkipOn999(sender), CleanupPolicy::Graceful);
+
+    // `Control::SkipRemaining` must unconditionally skip the cleanup
+    // drain even though this actor was built with `CleanupPolicy::Graceful`.
+    actor.tell(999).unwrap();
+    actor.tell(1).unwrap();
+    actor.wait();
+
+    let received: Vec<i32> = receiver.try_iter().collect();
+    assert_eq!(received, vec![999]);
+}
+
+#[test]
+fn test_system_disgraceful_drops_pending_on_kill() {
+    let system = System::new(1);
+    let (sender, receiver) = mpsc::channel();
+    let actor = system.spawn(Collect(sender), CleanupPolicy::Disgraceful);
+
+    // Kill before anything is ever sent, so the very first time a worker
+    // looks at this actor it must see `should_die` and refuse to drain.
+    actor.kill();
+    for k in 0..20 {
+        let _ = actor.tell(k);
+    }
+    actor.wait();
+
+    let received: Vec<i32> = receiver.try_iter().collect();
+    assert!(received.is_empty());
+}
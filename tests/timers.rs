@@ -0,0 +1,73 @@
+use std::{sync::mpsc, thread, time::Duration};
+
+use theatre::{Actor, Control, Interpreter};
+
+struct Collect(mpsc::Sender<i32>);
+
+impl Interpreter<i32> for Collect {
+    fn interpret(&mut self, message: i32) -> Control {
+        let _ = self.0.send(message);
+        Control::Continue
+    }
+}
+
+#[inline]
+fn sleep_ms(count: u64) {
+    thread::sleep(Duration::from_millis(count));
+}
+
+#[test]
+fn test_tell_later_delivers_after_delay() {
+    let (sender, receiver) = mpsc::channel();
+    let actor: Actor<i32> = Actor::graceful(Collect(sender));
+
+    actor.tell_later(42, Duration::from_millis(100));
+    assert!(receiver.try_recv().is_err());
+
+    sleep_ms(250);
+    assert_eq!(receiver.try_recv(), Ok(42));
+
+    actor.kill();
+    actor.wait();
+}
+
+#[test]
+fn test_tell_interval_repeats_until_cancelled() {
+    let (sender, receiver) = mpsc::channel();
+    let actor: Actor<i32> = Actor::graceful(Collect(sender));
+
+    let handle = actor.tell_interval(7, Duration::from_millis(30));
+    sleep_ms(160); // several ticks should have fired by now
+    handle.cancel();
+    sleep_ms(50); // let a tick already racing the cancel land, if any
+
+    let fired_before_settling = receiver.try_iter().count();
+    assert!(
+        fired_before_settling >= 2,
+        "expected multiple interval ticks, got {fired_before_settling}"
+    );
+
+    sleep_ms(150); // nothing further should arrive once cancelled
+    let fired_after_settling = receiver.try_iter().count();
+    assert_eq!(fired_after_settling, 0, "cancel() should stop further ticks");
+
+    actor.kill();
+    actor.wait();
+}
+
+#[test]
+fn test_tell_interval_stops_when_actor_dies() {
+    let (sender, receiver) = mpsc::channel();
+    let actor: Actor<i32> = Actor::graceful(Collect(sender));
+
+    let _handle = actor.tell_interval(1, Duration::from_millis(20));
+    sleep_ms(60);
+    actor.kill();
+    actor.wait();
+
+    // Drain whatever arrived before death, then make sure the timer
+    // thread noticed the dead actor and stopped sending more.
+    let _ = receiver.try_iter().count();
+    sleep_ms(100);
+    assert_eq!(receiver.try_iter().count(), 0);
+}